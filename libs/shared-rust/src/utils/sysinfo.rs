@@ -1,4 +1,5 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::fmt;
+use std::time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH};
 
 /// Returns the current Unix timestamp in seconds.
 pub fn unix_timestamp() -> u64 {
@@ -8,6 +9,256 @@ pub fn unix_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Error returned by [`unix_timestamp_checked`] when the system clock
+/// reports a time before the Unix epoch.
+#[derive(Debug)]
+pub struct ClockWentBackwards(SystemTimeError);
+
+impl fmt::Display for ClockWentBackwards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "system clock is before the Unix epoch: {}", self.0)
+    }
+}
+
+impl std::error::Error for ClockWentBackwards {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Returns the current Unix timestamp in seconds, or an error if the
+/// system clock reports a time before the Unix epoch, instead of
+/// silently yielding `0` like [`unix_timestamp`] does.
+pub fn unix_timestamp_checked() -> Result<u64, ClockWentBackwards> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(ClockWentBackwards)
+}
+
+/// Adds `duration` to `time`, returning `None` instead of panicking if the
+/// result would overflow the platform-representable range. Also returns
+/// `None` if `time` is before [`UNIX_EPOCH`].
+///
+/// `time` is reduced to a [`Duration`] since [`UNIX_EPOCH`] so the addition
+/// can go through [`Duration::checked_add`] rather than relying on
+/// [`SystemTime`]'s own arithmetic.
+pub fn checked_add(time: SystemTime, duration: Duration) -> Option<SystemTime> {
+    let since_epoch = time.duration_since(UNIX_EPOCH).ok()?;
+    let sum = since_epoch.checked_add(duration)?;
+    UNIX_EPOCH.checked_add(sum)
+}
+
+/// Subtracts `duration` from `time`, returning `None` instead of panicking
+/// if the result would fall before [`UNIX_EPOCH`] (or if `time` already
+/// does), even where the platform could represent such a time.
+///
+/// `time` is reduced to a [`Duration`] since [`UNIX_EPOCH`] so the
+/// subtraction can go through [`Duration::checked_sub`] rather than
+/// relying on [`SystemTime`]'s own arithmetic.
+pub fn checked_sub(time: SystemTime, duration: Duration) -> Option<SystemTime> {
+    let since_epoch = time.duration_since(UNIX_EPOCH).ok()?;
+    let diff = since_epoch.checked_sub(duration)?;
+    UNIX_EPOCH.checked_add(diff)
+}
+
+/// Returns the current Unix timestamp in whole nanoseconds.
+pub fn unix_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+/// Returns the current Unix timestamp in whole milliseconds.
+pub fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Returns a monotonic timestamp in nanoseconds, suitable for measuring
+/// elapsed intervals (never for wall-clock display).
+///
+/// Unlike `Instant`, this is computed from a raw `CLOCK_MONOTONIC` read
+/// (callers subtract two such reads) rather than trusting `Instant +
+/// Duration` arithmetic, which is known to round-trip incorrectly on
+/// aarch64-apple-darwin. The returned value has no defined relationship to
+/// the Unix epoch; only deltas between calls are meaningful.
+#[cfg(unix)]
+pub fn monotonic_nanos() -> u64 {
+    match raw_monotonic_timespec() {
+        Some(ts) => (ts.tv_sec as u64)
+            .saturating_mul(1_000_000_000)
+            .saturating_add(ts.tv_nsec as u64),
+        // `clock_gettime` failed (e.g. an unsupported clock id on this
+        // Unix variant); fall back to `Instant` rather than returning 0.
+        None => instant_monotonic_nanos(),
+    }
+}
+
+#[cfg(not(unix))]
+pub fn monotonic_nanos() -> u64 {
+    instant_monotonic_nanos()
+}
+
+/// Monotonic nanosecond count backed by `std::time::Instant`, which is
+/// monotonic (if not necessarily high-resolution) on all Rust tier-1
+/// targets. Used as the non-Unix implementation and as the fallback when
+/// a raw clock read fails.
+fn instant_monotonic_nanos() -> u64 {
+    use std::time::Instant;
+    static START: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+    let start = START.get_or_init(Instant::now);
+    start.elapsed().as_nanos() as u64
+}
+
+#[cfg(unix)]
+#[repr(C)]
+struct Timespec {
+    tv_sec: i64,
+    tv_nsec: i64,
+}
+
+// `CLOCK_MONOTONIC`'s numeric value is platform-specific: using the Linux
+// value (1) on macOS/BSD makes `clock_gettime` fail with `EINVAL` there.
+#[cfg(target_os = "macos")]
+const CLOCK_MONOTONIC: i32 = 6;
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+const CLOCK_MONOTONIC: i32 = 4;
+#[cfg(any(target_os = "openbsd", target_os = "netbsd"))]
+const CLOCK_MONOTONIC: i32 = 3;
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "openbsd",
+    target_os = "netbsd"
+)))]
+const CLOCK_MONOTONIC: i32 = 1;
+
+#[cfg(unix)]
+extern "C" {
+    fn clock_gettime(clock_id: i32, tp: *mut Timespec) -> i32;
+}
+
+/// Reads `CLOCK_MONOTONIC` directly via `clock_gettime`, bypassing
+/// `std::time::Instant` so two raw reads can be subtracted without going
+/// through platform `Instant` arithmetic. Returns `None` if the call
+/// fails (e.g. an unsupported clock id), rather than a zeroed result.
+#[cfg(unix)]
+fn raw_monotonic_timespec() -> Option<Timespec> {
+    let mut ts = Timespec { tv_sec: 0, tv_nsec: 0 };
+    // Safety: `ts` is a valid, correctly-sized, correctly-aligned
+    // destination for `clock_gettime`.
+    let ret = unsafe { clock_gettime(CLOCK_MONOTONIC, &mut ts) };
+    if ret != 0 {
+        return None;
+    }
+    Some(ts)
+}
+
+/// TAI64, TAI64N and TAI64NA external timestamp encoding.
+///
+/// TAI64 counts seconds of International Atomic Time since
+/// `1970-01-01 00:00:00 TAI`, biased by `2^62` so that every representable
+/// civil-time timestamp is a positive, lexicographically-ordered 64-bit
+/// label. TAI64N and TAI64NA append 32-bit nanosecond and attosecond
+/// fields respectively, giving a compact, ordered, wire-safe timestamp
+/// that (unlike Unix time) does not repeat or jump across leap seconds.
+///
+/// See <https://cr.yp.to/libtai/tai64.html> for the reference format.
+pub mod tai64 {
+    /// Offset of the TAI64 epoch (`1970-01-01 00:00:00 TAI`) from the
+    /// label's zero point, i.e. `2^62`. The Unix epoch lies
+    /// [`TAI_UTC_BASE_OFFSET_SECS`] after it.
+    const TAI64_EPOCH_OFFSET: u64 = 1 << 62;
+
+    /// The TAI-UTC offset (leap seconds elapsed since 1970) assumed by this
+    /// encoder. This crate treats it as fixed rather than consulting a
+    /// leap-second table, matching common TAI64 implementations that bake
+    /// in a base offset.
+    const TAI_UTC_BASE_OFFSET_SECS: u64 = 10;
+
+    /// Encodes a Unix timestamp (seconds since the epoch, plus a
+    /// nanosecond remainder) as a 12-byte big-endian TAI64N label.
+    ///
+    /// `nanos` is not validated here; callers passing a value outside
+    /// `0..=999_999_999` will get a label that round-trips through
+    /// [`decode_tai64n`] as `None`. Use [`decode_tai64n`] to validate.
+    pub fn encode_tai64n(unix_secs: u64, nanos: u32) -> [u8; 12] {
+        let label = TAI64_EPOCH_OFFSET
+            .saturating_add(unix_secs)
+            .saturating_add(TAI_UTC_BASE_OFFSET_SECS);
+        let mut out = [0u8; 12];
+        out[0..8].copy_from_slice(&label.to_be_bytes());
+        out[8..12].copy_from_slice(&nanos.to_be_bytes());
+        out
+    }
+
+    /// Decodes a 12-byte TAI64N label back into `(unix_secs, nanos)`.
+    ///
+    /// Returns `None` if `bytes` is not exactly 12 bytes long, if the
+    /// label falls below `TAI64_EPOCH_OFFSET` (`2^62`, below every label
+    /// [`encode_tai64n`] produces), or if the nanosecond field is outside
+    /// `0..=999_999_999`.
+    pub fn decode_tai64n(bytes: &[u8]) -> Option<(u64, u32)> {
+        if bytes.len() != 12 {
+            return None;
+        }
+        let mut label_bytes = [0u8; 8];
+        label_bytes.copy_from_slice(&bytes[0..8]);
+        let label = u64::from_be_bytes(label_bytes);
+        if label < TAI64_EPOCH_OFFSET {
+            return None;
+        }
+
+        let mut nanos_bytes = [0u8; 4];
+        nanos_bytes.copy_from_slice(&bytes[8..12]);
+        let nanos = u32::from_be_bytes(nanos_bytes);
+        if nanos > 999_999_999 {
+            return None;
+        }
+
+        let unix_secs = label
+            .saturating_sub(TAI64_EPOCH_OFFSET)
+            .saturating_sub(TAI_UTC_BASE_OFFSET_SECS);
+        Some((unix_secs, nanos))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let encoded = encode_tai64n(1_700_000_000, 123_456_789);
+            assert_eq!(decode_tai64n(&encoded), Some((1_700_000_000, 123_456_789)));
+        }
+
+        #[test]
+        fn test_rejects_wrong_length() {
+            assert_eq!(decode_tai64n(&[0u8; 11]), None);
+            assert_eq!(decode_tai64n(&[0u8; 13]), None);
+        }
+
+        #[test]
+        fn test_rejects_label_below_epoch_offset() {
+            let mut bytes = encode_tai64n(0, 0);
+            bytes[0] = 0;
+            assert_eq!(decode_tai64n(&bytes), None);
+        }
+
+        #[test]
+        fn test_rejects_out_of_range_nanos() {
+            let mut bytes = encode_tai64n(0, 0);
+            bytes[8..12].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+            assert_eq!(decode_tai64n(&bytes), None);
+        }
+    }
+}
+
 /// Returns the number of logical CPU cores available.
 pub fn cpu_count() -> usize {
     std::thread::available_parallelism()
@@ -15,6 +266,257 @@ pub fn cpu_count() -> usize {
         .unwrap_or(1)
 }
 
+/// Returns the CPU count from `var` if it is set to a positive integer,
+/// falling back to [`cpu_count`] when the variable is unset or unparseable.
+///
+/// This mirrors the historical `RUST_THREADS`-or-`num_cpus` resolution
+/// logic: an explicit override lets operators pin a worker-pool size from
+/// configuration without recompiling.
+pub fn cpu_count_env(var: &str) -> usize {
+    std::env::var(var)
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or_else(cpu_count)
+}
+
+/// Returns the CPU count actually available to this process, consulting
+/// CPU affinity and cgroup quotas on Linux so thread-pool sizing matches
+/// the real scheduling budget rather than the host's total core count.
+///
+/// On non-Linux platforms this is equivalent to [`cpu_count`].
+pub fn effective_cpu_count() -> usize {
+    let total = cpu_count();
+    #[cfg(target_os = "linux")]
+    {
+        let affinity = linux::affinity_cpu_count().unwrap_or(total);
+        let quota = linux::cgroup_quota_cpu_count().unwrap_or(total);
+        affinity.min(quota).min(total).max(1)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        total
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+
+    const CPU_SETSIZE_BYTES: usize = 128; // 1024 bits, glibc's default CPU_SETSIZE.
+
+    extern "C" {
+        fn sched_getaffinity(pid: i32, cpusetsize: usize, mask: *mut u8) -> i32;
+    }
+
+    /// Counts the CPUs in this process's `sched_getaffinity` mask, i.e.
+    /// the cores it is actually allowed to run on (respecting `taskset`,
+    /// `cpuset` cgroups, etc.).
+    pub(super) fn affinity_cpu_count() -> Option<usize> {
+        let mut mask = [0u8; CPU_SETSIZE_BYTES];
+        // Safety: `mask` is a valid buffer of the size passed in, and `pid
+        // == 0` requests the calling process's own affinity mask.
+        let ret = unsafe { sched_getaffinity(0, CPU_SETSIZE_BYTES, mask.as_mut_ptr()) };
+        if ret != 0 {
+            return None;
+        }
+        let count = mask.iter().map(|byte| byte.count_ones() as usize).sum();
+        if count == 0 {
+            None
+        } else {
+            Some(count)
+        }
+    }
+
+    /// Derives a CPU budget from the cgroup CPU quota, preferring cgroup v2
+    /// (`cpu.max`) and falling back to cgroup v1 (`cpu.cfs_quota_us` /
+    /// `cpu.cfs_period_us`). Returns `None` if no quota is configured
+    /// (quota `"max"` or `-1`) or the cgroup files are unreadable.
+    pub(super) fn cgroup_quota_cpu_count() -> Option<usize> {
+        if let Some(count) = cgroup_v2_quota_cpu_count() {
+            return Some(count);
+        }
+        cgroup_v1_quota_cpu_count()
+    }
+
+    fn cgroup_v2_quota_cpu_count() -> Option<usize> {
+        let contents = fs::read_to_string("/sys/fs/cgroup/cpu.max").ok()?;
+        let mut fields = contents.split_whitespace();
+        let quota = fields.next()?;
+        let period: u64 = fields.next()?.parse().ok()?;
+        if quota == "max" {
+            return None;
+        }
+        let quota: u64 = quota.parse().ok()?;
+        quota_to_cpu_count(quota, period)
+    }
+
+    fn cgroup_v1_quota_cpu_count() -> Option<usize> {
+        let quota: i64 = fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_quota_us")
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        if quota <= 0 {
+            return None;
+        }
+        let period: u64 = fs::read_to_string("/sys/fs/cgroup/cpu/cpu.cfs_period_us")
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        quota_to_cpu_count(quota as u64, period)
+    }
+
+    fn quota_to_cpu_count(quota: u64, period: u64) -> Option<usize> {
+        if period == 0 {
+            return None;
+        }
+        // Round up: a quota of 1.5 periods' worth of CPU time should still
+        // reserve 2 whole cores rather than truncating to 1.
+        Some(quota.div_ceil(period).max(1) as usize)
+    }
+}
+
+/// A file's modification, access and (where available) creation times, as
+/// Unix seconds plus a nanosecond remainder, matching the epoch/nanosecond
+/// representation used elsewhere in this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileTimes {
+    pub modified: (u64, u32),
+    pub accessed: (u64, u32),
+    /// `None` on filesystems/platforms that don't track a birthtime.
+    pub created: Option<(u64, u32)>,
+}
+
+fn to_unix_secs_nanos(time: SystemTime) -> (u64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => (d.as_secs(), d.subsec_nanos()),
+        Err(_) => (0, 0),
+    }
+}
+
+/// Reads `path`'s modified, accessed and (where available) created times.
+pub fn file_times(path: &std::path::Path) -> std::io::Result<FileTimes> {
+    let metadata = std::fs::metadata(path)?;
+    let modified = to_unix_secs_nanos(metadata.modified()?);
+    let accessed = to_unix_secs_nanos(metadata.accessed()?);
+    let created = metadata.created().ok().map(to_unix_secs_nanos);
+    Ok(FileTimes {
+        modified,
+        accessed,
+        created,
+    })
+}
+
+/// Sets `path`'s access and modification times to the given Unix
+/// `(seconds, nanoseconds)` pairs.
+#[cfg(unix)]
+pub fn set_file_times(
+    path: &std::path::Path,
+    atime: (u64, u32),
+    mtime: (u64, u32),
+) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::raw::c_char;
+    use std::os::unix::ffi::OsStrExt;
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: i64,
+        tv_nsec: i64,
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const AT_FDCWD: i32 = -2;
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    const AT_FDCWD: i32 = -100;
+
+    extern "C" {
+        fn utimensat(dirfd: i32, pathname: *const c_char, times: *const Timespec, flags: i32) -> i32;
+    }
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let times = [
+        Timespec {
+            tv_sec: atime.0 as i64,
+            tv_nsec: atime.1 as i64,
+        },
+        Timespec {
+            tv_sec: mtime.0 as i64,
+            tv_nsec: mtime.1 as i64,
+        },
+    ];
+    // Safety: `c_path` is a valid NUL-terminated string for the duration of
+    // the call, and `times` points to exactly the two entries `utimensat`
+    // expects.
+    let ret = unsafe { utimensat(AT_FDCWD, c_path.as_ptr(), times.as_ptr(), 0) };
+    if ret != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Sets `path`'s access and modification times to the given Unix
+/// `(seconds, nanoseconds)` pairs.
+#[cfg(windows)]
+pub fn set_file_times(
+    path: &std::path::Path,
+    atime: (u64, u32),
+    mtime: (u64, u32),
+) -> std::io::Result<()> {
+    use std::fs::OpenOptions;
+    use std::os::windows::io::AsRawHandle;
+
+    const FILETIME_UNIX_EPOCH_100NS: u64 = 116_444_736_000_000_000;
+
+    #[repr(C)]
+    struct FileTime {
+        lo: u32,
+        hi: u32,
+    }
+
+    fn to_filetime((secs, nanos): (u64, u32)) -> FileTime {
+        let ticks = FILETIME_UNIX_EPOCH_100NS
+            .saturating_add(secs.saturating_mul(10_000_000))
+            .saturating_add((nanos / 100) as u64);
+        FileTime {
+            lo: ticks as u32,
+            hi: (ticks >> 32) as u32,
+        }
+    }
+
+    extern "system" {
+        fn SetFileTime(
+            file: *mut std::ffi::c_void,
+            creation: *const FileTime,
+            last_access: *const FileTime,
+            last_write: *const FileTime,
+        ) -> i32;
+    }
+
+    // `.write(true)` already requests `GENERIC_WRITE`, which includes the
+    // `FILE_WRITE_ATTRIBUTES` access right `SetFileTime` needs.
+    let file = OpenOptions::new().write(true).open(path)?;
+    let access_time = to_filetime(atime);
+    let write_time = to_filetime(mtime);
+    // Safety: `file` stays open and its handle valid for the duration of
+    // the call, and both `FileTime` arguments are fully initialized.
+    let ret = unsafe {
+        SetFileTime(
+            file.as_raw_handle() as *mut std::ffi::c_void,
+            std::ptr::null(),
+            &access_time,
+            &write_time,
+        )
+    };
+    if ret == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,10 +527,97 @@ mod tests {
         assert!(ts > 0, "timestamp should be positive");
     }
 
+    #[test]
+    fn test_unix_nanos() {
+        let nanos = unix_nanos();
+        assert!(nanos > 0, "nanos should be positive");
+    }
+
+    #[test]
+    fn test_unix_millis() {
+        let millis = unix_millis();
+        assert!(millis > 0, "millis should be positive");
+    }
+
+    #[test]
+    fn test_monotonic_nanos_is_monotonic() {
+        let a = monotonic_nanos();
+        let b = monotonic_nanos();
+        assert!(b >= a, "successive monotonic reads must not go backwards");
+    }
+
     #[test]
     fn test_cpu_count() {
         let count = cpu_count();
         assert!(count >= 1, "should have at least 1 CPU");
     }
+
+    #[test]
+    fn test_unix_timestamp_checked() {
+        let ts = unix_timestamp_checked().expect("clock should be after the Unix epoch");
+        assert!(ts > 0, "timestamp should be positive");
+    }
+
+    #[test]
+    fn test_checked_add_overflow_returns_none() {
+        assert_eq!(checked_add(SystemTime::now(), Duration::MAX), None);
+    }
+
+    #[test]
+    fn test_checked_sub_before_epoch_returns_none() {
+        assert_eq!(checked_sub(UNIX_EPOCH, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn test_checked_add_sub_round_trip() {
+        let now = SystemTime::now();
+        let later = checked_add(now, Duration::from_secs(60)).expect("should not overflow");
+        let back = checked_sub(later, Duration::from_secs(60)).expect("should not underflow");
+        assert_eq!(back, now);
+    }
+
+    #[test]
+    fn test_cpu_count_env_falls_back_when_unset() {
+        let count = cpu_count_env("SHARED_RUST_TEST_CPU_COUNT_UNSET_VAR");
+        assert_eq!(count, cpu_count());
+    }
+
+    #[test]
+    fn test_cpu_count_env_falls_back_when_unparseable() {
+        std::env::set_var("SHARED_RUST_TEST_CPU_COUNT_BOGUS_VAR", "not-a-number");
+        let count = cpu_count_env("SHARED_RUST_TEST_CPU_COUNT_BOGUS_VAR");
+        assert_eq!(count, cpu_count());
+        std::env::remove_var("SHARED_RUST_TEST_CPU_COUNT_BOGUS_VAR");
+    }
+
+    #[test]
+    fn test_cpu_count_env_honors_override() {
+        std::env::set_var("SHARED_RUST_TEST_CPU_COUNT_OVERRIDE_VAR", "3");
+        assert_eq!(cpu_count_env("SHARED_RUST_TEST_CPU_COUNT_OVERRIDE_VAR"), 3);
+        std::env::remove_var("SHARED_RUST_TEST_CPU_COUNT_OVERRIDE_VAR");
+    }
+
+    #[test]
+    fn test_effective_cpu_count_at_least_one() {
+        assert!(effective_cpu_count() >= 1);
+    }
+
+    #[test]
+    fn test_file_times_round_trip() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("shared-rust-file-times-test-{:?}", std::thread::current().id()));
+        std::fs::write(&path, b"hello").expect("should create temp file");
+
+        let times = file_times(&path).expect("should read file times");
+        assert!(times.modified.0 > 0, "modified time should be positive");
+
+        set_file_times(&path, (1_600_000_000, 0), (1_700_000_000, 500))
+            .expect("should set file times");
+        let updated = file_times(&path).expect("should re-read file times");
+        assert_eq!(updated.accessed.0, 1_600_000_000);
+        assert_eq!(updated.modified.0, 1_700_000_000);
+
+        std::fs::remove_file(&path).expect("should clean up temp file");
+    }
 }
 